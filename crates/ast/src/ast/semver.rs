@@ -81,6 +81,94 @@ impl Ord for SemverVersionNumber {
     }
 }
 
+/// A single dot-separated pre-release or build-metadata identifier.
+///
+/// Per the SemVer 2.0 spec, an identifier is either fully numeric (compared numerically) or
+/// contains a non-digit and is compared as an ASCII string; numeric identifiers always have
+/// lower precedence than alphanumeric ones. A digit-only identifier with a leading zero, or one
+/// too large for a `u64`, is stored as [`AlphaNumeric`](Self::AlphaNumeric) and therefore also
+/// compares as a string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Identifier {
+    /// A purely numeric identifier, e.g. the `1` in `rc.1`.
+    Numeric(u64),
+    /// An alphanumeric identifier, e.g. `rc` in `rc.1`.
+    AlphaNumeric(String),
+}
+
+impl Identifier {
+    /// Classifies `s` as [`Numeric`](Self::Numeric) if it consists only of ASCII digits and has
+    /// no leading zero (so that formatting it back via [`Display`](fmt::Display) reproduces `s`
+    /// exactly), otherwise as [`AlphaNumeric`](Self::AlphaNumeric). A leading-zero numeric string
+    /// like `"0010"` is therefore kept as-is rather than losing its formatting as `10`.
+    fn parse(s: &str) -> Self {
+        let is_lossless_numeric = !s.is_empty()
+            && s.bytes().all(|b| b.is_ascii_digit())
+            && (s == "0" || !s.starts_with('0'));
+        if is_lossless_numeric {
+            if let Ok(n) = s.parse() {
+                return Self::Numeric(n);
+            }
+        }
+        Self::AlphaNumeric(s.to_owned())
+    }
+}
+
+impl fmt::Display for Identifier {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(n) => n.fmt(f),
+            Self::AlphaNumeric(s) => s.fmt(f),
+        }
+    }
+}
+
+impl PartialOrd for Identifier {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Identifier {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Numeric(a), Self::Numeric(b)) => a.cmp(b),
+            (Self::AlphaNumeric(a), Self::AlphaNumeric(b)) => a.cmp(b),
+            // Numeric identifiers always have lower precedence than alphanumeric identifiers.
+            (Self::Numeric(_), Self::AlphaNumeric(_)) => Ordering::Less,
+            (Self::AlphaNumeric(_), Self::Numeric(_)) => Ordering::Greater,
+        }
+    }
+}
+
+fn join_identifiers(ids: &[Identifier]) -> String {
+    ids.iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+fn parse_identifiers(s: &str) -> Vec<Identifier> {
+    if s.is_empty() {
+        return Vec::new();
+    }
+    s.split('.').map(Identifier::parse).collect()
+}
+
+/// Compares two pre-release identifier lists per SemVer 2.0 precedence rules: a non-empty list
+/// has *lower* precedence than an empty one, otherwise identifiers are compared left-to-right
+/// with a longer list winning once all shared identifiers are equal.
+fn cmp_pre(a: &[Identifier], b: &[Identifier]) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.iter().cmp(b.iter()),
+    }
+}
+
 /// A SemVer version.
 #[derive(Clone, Debug)]
 pub struct SemverVersion {
@@ -91,7 +179,12 @@ pub struct SemverVersion {
     pub minor: Option<SemverVersionNumber>,
     /// Patch version. Optional.
     pub patch: Option<SemverVersionNumber>,
-    // Pre-release and build metadata are not supported.
+    /// Pre-release identifiers, e.g. `rc.1` in `1.0.0-rc.1`. A non-empty list lowers precedence
+    /// relative to the same version without one.
+    pub pre: Vec<Identifier>,
+    /// Build metadata identifiers, e.g. `build.5` in `1.0.0+build.5`. Ignored when comparing
+    /// versions, but preserved for [`Display`](fmt::Display).
+    pub build: Vec<Identifier>,
 }
 
 impl PartialEq for SemverVersion {
@@ -125,12 +218,20 @@ impl Ord for SemverVersion {
             .cmp(&other.major)
             .then_with(|| cmp_opt(&self.minor, &other.minor))
             .then_with(|| cmp_opt(&self.patch, &other.patch))
+            .then_with(|| cmp_pre(&self.pre, &other.pre))
     }
 }
 
 impl fmt::Display for SemverVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let Self { span: _, major, minor, patch } = *self;
+        let Self {
+            span: _,
+            major,
+            minor,
+            patch,
+            pre,
+            build,
+        } = self;
         write!(f, "{major}")?;
         if let Some(minor) = minor {
             write!(f, ".{minor}")?;
@@ -141,6 +242,12 @@ impl fmt::Display for SemverVersion {
             }
             write!(f, ".{patch}")?;
         }
+        if !pre.is_empty() {
+            write!(f, "-{}", join_identifiers(pre))?;
+        }
+        if !build.is_empty() {
+            write!(f, "+{}", join_identifiers(build))?;
+        }
         Ok(())
     }
 }
@@ -153,6 +260,8 @@ impl From<semver::Version> for SemverVersion {
             major: version.major.into(),
             minor: Some(version.minor.into()),
             patch: Some(version.patch.into()),
+            pre: parse_identifiers(version.pre.as_str()),
+            build: parse_identifiers(version.build.as_str()),
         }
     }
 }
@@ -160,11 +269,16 @@ impl From<semver::Version> for SemverVersion {
 impl From<SemverVersion> for semver::Version {
     #[inline]
     fn from(version: SemverVersion) -> Self {
-        Self::new(
+        let mut v = Self::new(
             version.major.into(),
             version.minor.map(Into::into).unwrap_or(0),
             version.patch.map(Into::into).unwrap_or(0),
-        )
+        );
+        v.pre = semver::Prerelease::new(&join_identifiers(&version.pre))
+            .unwrap_or(semver::Prerelease::EMPTY);
+        v.build = semver::BuildMetadata::new(&join_identifiers(&version.build))
+            .unwrap_or(semver::BuildMetadata::EMPTY);
+        v
     }
 }
 
@@ -176,7 +290,9 @@ impl SemverVersion {
     }
 }
 
-/// A SemVer version requirement. This is a list of components, and is never empty.
+/// A SemVer version requirement. This is a list of components, and is never empty, *except*
+/// for the result of [`SemverReq::intersect`] when the intersection is unsatisfiable, in which
+/// case `dis` is empty; see that method's docs.
 #[derive(Clone, Debug)]
 pub struct SemverReq {
     /// The components of this requirement.
@@ -204,6 +320,60 @@ impl SemverReq {
     pub fn matches(&self, version: &SemverVersion) -> bool {
         self.dis.iter().any(|c| c.matches(version))
     }
+
+    /// Returns the highest version among `candidates` that satisfies this requirement, if any.
+    pub fn max_satisfying<I: IntoIterator<Item = SemverVersion>>(
+        &self,
+        candidates: I,
+    ) -> Option<SemverVersion> {
+        candidates.into_iter().filter(|v| self.matches(v)).max()
+    }
+
+    /// Returns the lowest version among `candidates` that satisfies this requirement, if any.
+    pub fn min_satisfying<I: IntoIterator<Item = SemverVersion>>(
+        &self,
+        candidates: I,
+    ) -> Option<SemverVersion> {
+        candidates.into_iter().filter(|v| self.matches(v)).min()
+    }
+
+    /// Returns `true` if any version in `candidates` satisfies this requirement.
+    pub fn is_satisfied_by_any<I: IntoIterator<Item = SemverVersion>>(
+        &self,
+        candidates: I,
+    ) -> bool {
+        candidates.into_iter().any(|v| self.matches(&v))
+    }
+
+    /// Returns `true` if at least one of this requirement's conjoined component sets is
+    /// satisfiable, i.e. has a combined lower bound that does not exceed its combined upper
+    /// bound. See [`SemverReqCon::is_satisfiable`].
+    pub fn is_satisfiable(&self) -> bool {
+        self.dis.iter().any(SemverReqCon::is_satisfiable)
+    }
+
+    /// Computes the intersection of this requirement with `other`.
+    ///
+    /// This distributes the or-of-ands structure described in [`Self::dis`]: every conjoined
+    /// component set of `self` is combined with every conjoined component set of `other`,
+    /// keeping only the combinations that are [satisfiable](SemverReqCon::is_satisfiable). The
+    /// result is itself normalized in the sense that every remaining `dis` entry is satisfiable,
+    /// but an entirely unsatisfiable intersection is represented as an empty `dis` list rather
+    /// than an error, so that callers can report it alongside the spans of the offending
+    /// pragmas: each merged [`SemverReqCon::span`] covers both of the conjoined sides' spans.
+    pub fn intersect(&self, other: &Self) -> Self {
+        let dis = self
+            .dis
+            .iter()
+            .flat_map(|a| other.dis.iter().map(move |b| (a, b)))
+            .map(|(a, b)| SemverReqCon {
+                span: a.span.to(b.span),
+                components: a.components.iter().chain(&b.components).cloned().collect(),
+            })
+            .filter(SemverReqCon::is_satisfiable)
+            .collect();
+        Self { dis }
+    }
 }
 
 /// A list of conjoint SemVer version requirement components.
@@ -231,6 +401,36 @@ impl SemverReqCon {
     pub fn matches(&self, version: &SemverVersion) -> bool {
         self.components.iter().all(|c| c.matches(version))
     }
+
+    /// Returns `true` if this conjoined set of components is internally satisfiable, i.e. its
+    /// combined lower bound (the max of all `>=`/`>`/`~`/`^`/`=`/range-start constraints) does
+    /// not exceed its combined upper bound (the min of all `<=`/`<`/range-end constraints).
+    /// Wildcards impose no bound in either direction.
+    ///
+    /// Bound strictness (`<`/`>` vs `<=`/`>=`) is tracked alongside the version: if the combined
+    /// bounds land on the same version, the range is only satisfiable when both sides are
+    /// inclusive (e.g. `>=0.8.20` and `<0.8.20` combined are unsatisfiable, since no version is
+    /// both `>= 0.8.20` and `< 0.8.20`).
+    pub fn is_satisfiable(&self) -> bool {
+        let lo = self
+            .components
+            .iter()
+            .filter_map(SemverReqComponent::lower_bound)
+            .max_by(|(a, a_incl), (b, b_incl)| a.cmp(b).then((!a_incl).cmp(&!b_incl)));
+        let hi = self
+            .components
+            .iter()
+            .filter_map(SemverReqComponent::upper_bound)
+            .min_by(|(a, a_incl), (b, b_incl)| a.cmp(b).then(a_incl.cmp(b_incl)));
+        match (lo, hi) {
+            (Some((lo, lo_incl)), Some((hi, hi_incl))) => match lo.cmp(&hi) {
+                Ordering::Less => true,
+                Ordering::Equal => lo_incl && hi_incl,
+                Ordering::Greater => false,
+            },
+            _ => true,
+        }
+    }
 }
 
 /// A single SemVer version requirement component.
@@ -251,6 +451,18 @@ impl SemverReqComponent {
     pub fn matches(&self, version: &SemverVersion) -> bool {
         self.kind.matches(version)
     }
+
+    /// See [`SemverReqCon::is_satisfiable`]. The `bool` is `true` if the bound is inclusive
+    /// (`>=`/`<=`) and `false` if it is strict (`>`/`<`).
+    fn lower_bound(&self) -> Option<(SemverVersion, bool)> {
+        self.kind.lower_bound()
+    }
+
+    /// See [`SemverReqCon::is_satisfiable`]. The `bool` is `true` if the bound is inclusive
+    /// (`>=`/`<=`) and `false` if it is strict (`>`/`<`).
+    fn upper_bound(&self) -> Option<(SemverVersion, bool)> {
+        self.kind.upper_bound()
+    }
 }
 
 /// A SemVer version requirement component.
@@ -297,6 +509,180 @@ impl SemverReqComponentKind {
             }
         }
     }
+
+    /// See [`SemverReqCon::is_satisfiable`]. The `bool` is `true` if the bound is inclusive
+    /// (`>=`/`<=`) and `false` if it is strict (`>`/`<`).
+    fn lower_bound(&self) -> Option<(SemverVersion, bool)> {
+        match self {
+            Self::Op(op, v) => match op.unwrap_or(Op::Exact) {
+                Op::GreaterEq | Op::Tilde | Op::Caret | Op::Exact => Some((v.clone(), true)),
+                Op::Greater => Some((v.clone(), false)),
+                _ => None,
+            },
+            Self::Range(start, _) => Some((start.clone(), true)),
+        }
+    }
+
+    /// See [`SemverReqCon::is_satisfiable`]. The `bool` is `true` if the bound is inclusive
+    /// (`>=`/`<=`) and `false` if it is strict (`>`/`<`).
+    fn upper_bound(&self) -> Option<(SemverVersion, bool)> {
+        match self {
+            Self::Op(op, v) => match op.unwrap_or(Op::Exact) {
+                Op::LessEq | Op::Exact => Some((v.clone(), true)),
+                Op::Less => Some((v.clone(), false)),
+                // Same truncation as `matches_tilde`: clearing `patch` makes the comparison
+                // ignore it (see [`SemverVersion::cmp`]'s `cmp_opt`), so this bounds `a` to the
+                // same major.minor as `v`.
+                Op::Tilde => {
+                    let mut v = v.clone();
+                    v.patch = None;
+                    Some((v, true))
+                }
+                // Same truncation as `matches_caret`: bounds `a` to the same major (or the same
+                // major.minor when `major` is `0`).
+                Op::Caret => {
+                    let mut v = v.clone();
+                    if v.major > SemverVersionNumber::Number(0) {
+                        v.minor = None;
+                    }
+                    v.patch = None;
+                    Some((v, true))
+                }
+                _ => None,
+            },
+            Self::Range(_, end) => Some((end.clone(), true)),
+        }
+    }
+}
+
+/// Error returned by the [`SemverReq`] to [`semver::VersionReq`] conversion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnrepresentableReq {
+    /// `semver::VersionReq` has a single AND-ed list of comparators and no way to express
+    /// Solidity's `||` disjunction, so only a requirement with a single [`SemverReq::dis`] entry
+    /// can be represented.
+    Disjunction,
+    /// The requirement's `dis` is empty (e.g. the unsatisfiable result of
+    /// [`SemverReq::intersect`]), so there are no comparators to build at all.
+    Unsatisfiable,
+    /// A non-wildcard component has a wildcard `major` (e.g. `*.2.3`). `semver::Comparator::major`
+    /// is a mandatory `u64`, so there is no way to represent "any major" outside of the bare `*`
+    /// wildcard op, which `semver` models by omitting `minor`/`patch` instead.
+    WildcardMajor,
+}
+
+impl fmt::Display for UnrepresentableReq {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Disjunction => {
+                "semver::VersionReq cannot represent a `||` disjunction of requirements"
+            }
+            Self::Unsatisfiable => {
+                "semver::VersionReq cannot represent an unsatisfiable requirement"
+            }
+            Self::WildcardMajor => "semver::VersionReq cannot represent a wildcard major version",
+        })
+    }
+}
+
+impl std::error::Error for UnrepresentableReq {}
+
+impl TryFrom<&SemverReq> for semver::VersionReq {
+    type Error = UnrepresentableReq;
+
+    /// Converts a single conjoined requirement into a `semver::VersionReq`.
+    ///
+    /// This is lossy: Solidity's `||` disjunction has no `semver` equivalent (see
+    /// [`UnrepresentableReq`]), and Solc's `~`/`^` semantics around missing minor/patch
+    /// components aren't identical to `semver`'s, even though both map to the same [`Op`].
+    fn try_from(req: &SemverReq) -> Result<Self, Self::Error> {
+        let [con] = &req.dis[..] else {
+            return Err(if req.dis.is_empty() {
+                UnrepresentableReq::Unsatisfiable
+            } else {
+                UnrepresentableReq::Disjunction
+            });
+        };
+        let mut comparators = Vec::with_capacity(con.components.len());
+        for component in &con.components {
+            match &component.kind {
+                SemverReqComponentKind::Op(op, v) => {
+                    comparators.push(to_comparator(op.unwrap_or(Op::Exact), v)?);
+                }
+                SemverReqComponentKind::Range(start, end) => {
+                    comparators.push(to_comparator(Op::GreaterEq, start)?);
+                    comparators.push(to_comparator(Op::LessEq, end)?);
+                }
+            }
+        }
+        Ok(Self { comparators })
+    }
+}
+
+fn to_comparator(op: Op, v: &SemverVersion) -> Result<semver::Comparator, UnrepresentableReq> {
+    if op == Op::Wildcard {
+        return Ok(semver::Comparator {
+            op,
+            major: 0,
+            minor: None,
+            patch: None,
+            pre: semver::Prerelease::EMPTY,
+        });
+    }
+    // `semver::Comparator::major` is a mandatory `u64`, so a wildcard major can only be
+    // represented by the bare `*` op handled above.
+    if matches!(v.major, SemverVersionNumber::Wildcard) {
+        return Err(UnrepresentableReq::WildcardMajor);
+    }
+    Ok(semver::Comparator {
+        op,
+        major: v.major.into(),
+        minor: number_to_comparator_part(v.minor),
+        patch: number_to_comparator_part(v.patch),
+        pre: semver::Prerelease::new(&join_identifiers(&v.pre))
+            .unwrap_or(semver::Prerelease::EMPTY),
+    })
+}
+
+fn number_to_comparator_part(n: Option<SemverVersionNumber>) -> Option<u64> {
+    match n? {
+        SemverVersionNumber::Number(n) => Some(n as u64),
+        SemverVersionNumber::Wildcard => None,
+    }
+}
+
+impl From<semver::VersionReq> for SemverReq {
+    /// Rebuilds a requirement from a `semver::VersionReq`'s comma-joined comparator group.
+    ///
+    /// `semver::VersionReq` has no `||` disjunction, so the result always has a single
+    /// [`SemverReq::dis`] entry conjoining one component per comparator.
+    fn from(req: semver::VersionReq) -> Self {
+        let components = req
+            .comparators
+            .iter()
+            .map(|c| SemverReqComponent {
+                span: Span::DUMMY,
+                kind: SemverReqComponentKind::Op(Some(c.op), from_comparator(c)),
+            })
+            .collect();
+        Self {
+            dis: vec![SemverReqCon {
+                span: Span::DUMMY,
+                components,
+            }],
+        }
+    }
+}
+
+fn from_comparator(c: &semver::Comparator) -> SemverVersion {
+    SemverVersion {
+        span: Span::DUMMY,
+        major: c.major.into(),
+        minor: c.minor.map(Into::into),
+        patch: c.patch.map(Into::into),
+        pre: parse_identifiers(c.pre.as_str()),
+        build: Vec::new(),
+    }
 }
 
 fn matches_op(op: Op, a: &SemverVersion, b: &SemverVersion) -> bool {
@@ -338,4 +724,299 @@ fn matches_caret(a: &SemverVersion, b: &SemverVersion) -> bool {
     matches_op(Op::LessEq, &a, b)
 }
 
-// Tests in `crates/parse/src/parser/item.rs`
+// Grammar-level parsing tests are in `crates/parse/src/parser/item.rs`; pure-logic unit tests
+// for this module live below.
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(major: u32, minor: u32, patch: u32) -> SemverVersion {
+        SemverVersion {
+            span: Span::DUMMY,
+            major: SemverVersionNumber::Number(major),
+            minor: Some(SemverVersionNumber::Number(minor)),
+            patch: Some(SemverVersionNumber::Number(patch)),
+            pre: Vec::new(),
+            build: Vec::new(),
+        }
+    }
+
+    fn pre(ids: &[&str]) -> Vec<Identifier> {
+        ids.iter().map(|s| Identifier::parse(s)).collect()
+    }
+
+    fn vpre(major: u32, minor: u32, patch: u32, pre_ids: &[&str]) -> SemverVersion {
+        SemverVersion {
+            pre: pre(pre_ids),
+            ..v(major, minor, patch)
+        }
+    }
+
+    fn op(kind: Op, version: SemverVersion) -> SemverReqComponent {
+        SemverReqComponent {
+            span: Span::DUMMY,
+            kind: SemverReqComponentKind::Op(Some(kind), version),
+        }
+    }
+
+    fn con(components: Vec<SemverReqComponent>) -> SemverReqCon {
+        SemverReqCon {
+            span: Span::DUMMY,
+            components,
+        }
+    }
+
+    fn req(dis: Vec<SemverReqCon>) -> SemverReq {
+        SemverReq { dis }
+    }
+
+    #[test]
+    fn caret_is_unsatisfiable_against_next_minor() {
+        // `^0.8.0` only allows `0.8.x`, so combined with `>=0.9.0` there's no valid version.
+        let r = req(vec![con(vec![
+            op(Op::Caret, v(0, 8, 0)),
+            op(Op::GreaterEq, v(0, 9, 0)),
+        ])]);
+        assert!(!r.is_satisfiable());
+    }
+
+    #[test]
+    fn exact_is_unsatisfiable_against_higher_lower_bound() {
+        let r = req(vec![con(vec![
+            op(Op::Exact, v(1, 0, 0)),
+            op(Op::GreaterEq, v(2, 0, 0)),
+        ])]);
+        assert!(!r.is_satisfiable());
+    }
+
+    #[test]
+    fn strict_less_is_unsatisfiable_against_same_inclusive_lower_bound() {
+        // No version is both `>=0.8.20` and `<0.8.20`, even though the bound values are equal.
+        let r = req(vec![con(vec![
+            op(Op::GreaterEq, v(0, 8, 20)),
+            op(Op::Less, v(0, 8, 20)),
+        ])]);
+        assert!(!r.is_satisfiable());
+    }
+
+    #[test]
+    fn strict_greater_is_unsatisfiable_against_same_exact_bound() {
+        let r = req(vec![con(vec![
+            op(Op::Greater, v(1, 0, 0)),
+            op(Op::Exact, v(1, 0, 0)),
+        ])]);
+        assert!(!r.is_satisfiable());
+    }
+
+    #[test]
+    fn inclusive_bounds_at_same_version_are_satisfiable() {
+        let r = req(vec![con(vec![
+            op(Op::GreaterEq, v(1, 0, 0)),
+            op(Op::LessEq, v(1, 0, 0)),
+        ])]);
+        assert!(r.is_satisfiable());
+    }
+
+    #[test]
+    fn intersect_of_adjoining_strict_and_inclusive_pragmas_is_unsatisfiable() {
+        // A realistic cross-file case: one file requires `<0.8.20`, another `>=0.8.20`.
+        let a = req(vec![con(vec![op(Op::Less, v(0, 8, 20))])]);
+        let b = req(vec![con(vec![op(Op::GreaterEq, v(0, 8, 20))])]);
+        let i = a.intersect(&b);
+        assert!(i.dis.is_empty());
+        assert!(!i.is_satisfiable());
+    }
+
+    #[test]
+    fn caret_with_major_zero_allows_patch_bumps() {
+        let r = req(vec![con(vec![
+            op(Op::Caret, v(0, 8, 0)),
+            op(Op::GreaterEq, v(0, 8, 5)),
+        ])]);
+        assert!(r.is_satisfiable());
+    }
+
+    #[test]
+    fn caret_with_nonzero_major_allows_minor_bumps() {
+        let r = req(vec![con(vec![
+            op(Op::Caret, v(1, 2, 3)),
+            op(Op::LessEq, v(1, 9, 0)),
+        ])]);
+        assert!(r.is_satisfiable());
+    }
+
+    #[test]
+    fn intersect_drops_unsatisfiable_combinations() {
+        let a = req(vec![con(vec![op(Op::Caret, v(0, 8, 0))])]);
+        let b = req(vec![con(vec![op(Op::GreaterEq, v(0, 9, 0))])]);
+        let i = a.intersect(&b);
+        assert!(i.dis.is_empty());
+        assert!(!i.is_satisfiable());
+    }
+
+    #[test]
+    fn intersect_keeps_satisfiable_combinations() {
+        let a = req(vec![con(vec![op(Op::GreaterEq, v(1, 0, 0))])]);
+        let b = req(vec![con(vec![op(Op::LessEq, v(2, 0, 0))])]);
+        let i = a.intersect(&b);
+        assert_eq!(i.dis.len(), 1);
+        assert_eq!(i.dis[0].components.len(), 2);
+        assert!(i.is_satisfiable());
+    }
+
+    #[test]
+    fn max_and_min_satisfying_pick_bounds_among_matches() {
+        let r = req(vec![con(vec![
+            op(Op::GreaterEq, v(1, 0, 0)),
+            op(Op::LessEq, v(2, 0, 0)),
+        ])]);
+        let candidates = vec![v(0, 9, 0), v(1, 5, 0), v(2, 0, 0), v(3, 0, 0)];
+        assert_eq!(r.max_satisfying(candidates.clone()), Some(v(2, 0, 0)));
+        assert_eq!(r.min_satisfying(candidates.clone()), Some(v(1, 5, 0)));
+        assert!(r.is_satisfied_by_any(candidates));
+    }
+
+    #[test]
+    fn max_satisfying_is_none_when_nothing_matches() {
+        let r = req(vec![con(vec![op(Op::GreaterEq, v(5, 0, 0))])]);
+        let candidates = vec![v(1, 0, 0), v(2, 0, 0)];
+        assert_eq!(r.max_satisfying(candidates.clone()), None);
+        assert_eq!(r.min_satisfying(candidates.clone()), None);
+        assert!(!r.is_satisfied_by_any(candidates));
+    }
+
+    #[test]
+    fn try_from_range_expands_to_two_comparators() {
+        let r = req(vec![con(vec![SemverReqComponent {
+            span: Span::DUMMY,
+            kind: SemverReqComponentKind::Range(v(1, 0, 0), v(2, 0, 0)),
+        }])]);
+        let vr = semver::VersionReq::try_from(&r).unwrap();
+        assert_eq!(vr.comparators.len(), 2);
+        assert_eq!(vr.comparators[0].op, Op::GreaterEq);
+        assert_eq!(vr.comparators[1].op, Op::LessEq);
+    }
+
+    #[test]
+    fn try_from_disjunction_is_unrepresentable() {
+        let r = req(vec![
+            con(vec![op(Op::GreaterEq, v(1, 0, 0))]),
+            con(vec![op(Op::GreaterEq, v(2, 0, 0))]),
+        ]);
+        assert_eq!(
+            semver::VersionReq::try_from(&r).unwrap_err(),
+            UnrepresentableReq::Disjunction
+        );
+    }
+
+    #[test]
+    fn try_from_empty_dis_is_unsatisfiable() {
+        let r = req(vec![]);
+        assert_eq!(
+            semver::VersionReq::try_from(&r).unwrap_err(),
+            UnrepresentableReq::Unsatisfiable
+        );
+    }
+
+    #[test]
+    fn try_from_wildcard_major_op_is_unrepresentable() {
+        let mut version = v(0, 2, 3);
+        version.major = SemverVersionNumber::Wildcard;
+        let r = req(vec![con(vec![op(Op::GreaterEq, version)])]);
+        assert_eq!(
+            semver::VersionReq::try_from(&r).unwrap_err(),
+            UnrepresentableReq::WildcardMajor
+        );
+    }
+
+    #[test]
+    fn try_from_wildcard_major_range_is_unrepresentable() {
+        let mut start = v(0, 0, 0);
+        start.major = SemverVersionNumber::Wildcard;
+        let r = req(vec![con(vec![SemverReqComponent {
+            span: Span::DUMMY,
+            kind: SemverReqComponentKind::Range(start, v(2, 0, 0)),
+        }])]);
+        assert_eq!(
+            semver::VersionReq::try_from(&r).unwrap_err(),
+            UnrepresentableReq::WildcardMajor
+        );
+    }
+
+    #[test]
+    fn try_from_bare_wildcard_is_representable() {
+        let r = req(vec![con(vec![op(Op::Wildcard, v(0, 0, 0))])]);
+        let vr = semver::VersionReq::try_from(&r).unwrap();
+        assert_eq!(vr.comparators.len(), 1);
+        assert_eq!(vr.comparators[0].major, 0);
+    }
+
+    #[test]
+    fn version_req_round_trips_through_semver() {
+        let r = req(vec![con(vec![
+            op(Op::GreaterEq, v(1, 0, 0)),
+            op(Op::Less, v(2, 0, 0)),
+        ])]);
+        let vr = semver::VersionReq::try_from(&r).unwrap();
+        let back = SemverReq::from(vr);
+        assert_eq!(back.dis.len(), 1);
+        assert_eq!(back.dis[0].components.len(), 2);
+        assert!(back.matches(&v(1, 5, 0)));
+        assert!(!back.matches(&v(2, 0, 0)));
+    }
+
+    #[test]
+    fn identifier_parse_distinguishes_numeric_and_alphanumeric() {
+        assert_eq!(Identifier::parse("1"), Identifier::Numeric(1));
+        assert_eq!(
+            Identifier::parse("alpha"),
+            Identifier::AlphaNumeric("alpha".into())
+        );
+        // A leading-zero numeric string is kept alphanumeric for lossless round-tripping.
+        assert_eq!(
+            Identifier::parse("01"),
+            Identifier::AlphaNumeric("01".into())
+        );
+    }
+
+    #[test]
+    fn pre_release_precedence_chain_follows_semver_spec() {
+        // https://semver.org/#spec-item-11
+        let chain = [
+            vpre(1, 0, 0, &["alpha"]),
+            vpre(1, 0, 0, &["alpha", "1"]),
+            vpre(1, 0, 0, &["alpha", "beta"]),
+            vpre(1, 0, 0, &["beta"]),
+            vpre(1, 0, 0, &["beta", "2"]),
+            vpre(1, 0, 0, &["beta", "11"]),
+            vpre(1, 0, 0, &["rc", "1"]),
+            v(1, 0, 0),
+        ];
+        for pair in chain.windows(2) {
+            assert!(pair[0] < pair[1], "{} should be < {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn numeric_pre_release_identifiers_compare_numerically() {
+        // `9` < `10` numerically, even though `"10"` < `"9"` lexicographically.
+        assert!(vpre(1, 0, 0, &["9"]) < vpre(1, 0, 0, &["10"]));
+    }
+
+    #[test]
+    fn build_metadata_is_ignored_in_comparison() {
+        let mut a = v(1, 0, 0);
+        a.build = pre(&["build", "1"]);
+        let mut b = v(1, 0, 0);
+        b.build = pre(&["build", "2"]);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn display_round_trips_pre_and_build() {
+        let mut version = vpre(1, 0, 0, &["rc", "1"]);
+        version.build = pre(&["build", "5"]);
+        assert_eq!(version.to_string(), "1.0.0-rc.1+build.5");
+    }
+}